@@ -1,109 +1,175 @@
 use crate::tokens::Token;
 
-pub trait Node {
-    fn token_literal(&self) -> String;
+/// Owned AST node shared by the parser, evaluator, and `Object`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Node {
+    LetStatement {
+        name: Box<Node>,
+        value: Option<Box<Node>>,
+    },
+    ReturnStatement {
+        value: Option<Box<Node>>,
+    },
+    ExpressionStatement {
+        token: Token,
+        expression: Option<Box<Node>>,
+    },
+    Identifier {
+        value: Token,
+    },
+    IntegerLiteral {
+        token: Token,
+        value: i64,
+    },
+    StringLiteral {
+        token: Token,
+        value: String,
+    },
+    PrefixExpression {
+        token: Token,
+        operator: String,
+        right: Box<Node>,
+    },
+    InfixExpression {
+        token: Token,
+        left: Box<Node>,
+        operator: String,
+        right: Box<Node>,
+    },
+    BooleanLiteral {
+        token: Token,
+        value: bool,
+    },
+    BlockStatement {
+        statements: Vec<Node>,
+    },
+    IfExpression {
+        token: Token,
+        condition: Box<Node>,
+        consequence: Box<Node>,
+        alternative: Option<Box<Node>>,
+    },
+    FunctionLiteral {
+        token: Token,
+        parameters: Vec<Node>,
+        body: Box<Node>,
+    },
+    CallExpression {
+        token: Token,
+        function: Box<Node>,
+        arguments: Vec<Node>,
+    },
 }
 
-pub trait Statement: Node {
-    fn name(&self) -> String;
-    fn value(&self) -> Option<String>;
-}
-
-pub trait Expression: Node {}
-
-#[derive(Default)]
-pub struct Program {
-    pub statements: Vec<Box<dyn Statement>>,
-}
-
-impl Node for Program {
-    fn token_literal(&self) -> String {
-        match self.statements.len() {
-            0 => String::from(""),
-            _ => self.statements[0].token_literal(),
+impl Node {
+    pub fn token_literal(&self) -> String {
+        match self {
+            Node::LetStatement { .. } => String::from("let"),
+            Node::ReturnStatement { .. } => String::from("return"),
+            Node::ExpressionStatement { token, .. } => token.v.clone(),
+            Node::Identifier { value } => value.v.clone(),
+            Node::IntegerLiteral { token, .. } => token.v.clone(),
+            Node::StringLiteral { token, .. } => token.v.clone(),
+            Node::PrefixExpression { token, .. } => token.v.clone(),
+            Node::InfixExpression { token, .. } => token.v.clone(),
+            Node::BooleanLiteral { token, .. } => token.v.clone(),
+            Node::BlockStatement { statements } => statements
+                .first()
+                .map(|s| s.token_literal())
+                .unwrap_or_default(),
+            Node::IfExpression { token, .. } => token.v.clone(),
+            Node::FunctionLiteral { token, .. } => token.v.clone(),
+            Node::CallExpression { token, .. } => token.v.clone(),
         }
     }
-}
-
-#[derive(Default)]
-pub struct LetStatement<'a> {
-    pub token: Token,
-    pub name: Identifier,
-    pub value: Option<&'a dyn Expression>,
-}
-
-impl<'a> LetStatement<'a> {
-    pub fn new(token: Token, name: Identifier, value: Option<&'a dyn Expression>) -> Self {
-        Self { token, name, value }
-    }
-}
-
-impl Node for LetStatement<'_> {
-    fn token_literal(&self) -> String {
-        self.token.v.clone()
-    }
-}
-
-impl Statement for LetStatement<'_> {
-    fn name(&self) -> String {
-        self.name.token_literal()
-    }
 
-    fn value(&self) -> Option<String> {
-        match self.value {
-            Some(v) => Some(v.token_literal()),
-            None => None,
+    pub fn as_string(&self) -> String {
+        match self {
+            Node::LetStatement { name, value } => format!(
+                "let {} = {};",
+                name.as_string(),
+                value.as_ref().map(|v| v.as_string()).unwrap_or_default()
+            ),
+            Node::ReturnStatement { value } => format!(
+                "return {};",
+                value.as_ref().map(|v| v.as_string()).unwrap_or_default()
+            ),
+            Node::ExpressionStatement { expression, .. } => {
+                expression.as_ref().map(|e| e.as_string()).unwrap_or_default()
+            }
+            Node::Identifier { value } => value.v.clone(),
+            Node::IntegerLiteral { token, .. } => token.v.clone(),
+            Node::StringLiteral { token, .. } => token.v.clone(),
+            Node::PrefixExpression {
+                operator, right, ..
+            } => format!("({}{})", operator, right.as_string()),
+            Node::InfixExpression {
+                left,
+                operator,
+                right,
+                ..
+            } => format!("({} {} {})", left.as_string(), operator, right.as_string()),
+            Node::BooleanLiteral { token, .. } => token.v.clone(),
+            Node::BlockStatement { statements } => statements
+                .iter()
+                .map(|s| s.as_string())
+                .collect::<Vec<String>>()
+                .join(""),
+            Node::IfExpression {
+                condition,
+                consequence,
+                alternative,
+                ..
+            } => match alternative {
+                Some(alt) => format!(
+                    "if{} {} else {}",
+                    condition.as_string(),
+                    consequence.as_string(),
+                    alt.as_string()
+                ),
+                None => format!("if{} {}", condition.as_string(), consequence.as_string()),
+            },
+            Node::FunctionLiteral {
+                token,
+                parameters,
+                body,
+            } => format!(
+                "{}({}) {}",
+                token.v,
+                parameters
+                    .iter()
+                    .map(|p| p.as_string())
+                    .collect::<Vec<String>>()
+                    .join(", "),
+                body.as_string()
+            ),
+            Node::CallExpression {
+                function,
+                arguments,
+                ..
+            } => format!(
+                "{}({})",
+                function.as_string(),
+                arguments
+                    .iter()
+                    .map(|a| a.as_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
         }
     }
 }
 
 #[derive(Default)]
-pub struct ReturnStatement<'a>{
-    pub token: Token,
-    pub value: Option<&'a dyn Expression>,
-}
-
-impl<'a> ReturnStatement<'a> {
-    pub fn new(token: Token, value: Option<&'a dyn Expression>) -> Self {
-        Self { token, value }
-    }
-}
-
-impl Node for ReturnStatement<'_> {
-    fn token_literal(&self) -> String {
-        self.token.v.clone()
-    }
+pub struct Program {
+    pub statements: Vec<Node>,
 }
 
-impl Statement for ReturnStatement<'_> {
-    fn name(&self) -> String {
-        "".to_owned()
-    }
-
-    fn value(&self) -> Option<String> {
-        match self.value {
-            Some(v) => Some(v.token_literal()),
-            None => None,
+impl Program {
+    pub fn token_literal(&self) -> String {
+        match self.statements.first() {
+            Some(stmt) => stmt.token_literal(),
+            None => String::new(),
         }
     }
 }
-
-#[derive(Default)]
-pub struct Identifier {
-    token: Token,
-    v: String,
-}
-
-impl Identifier {
-    pub fn new(token: Token, v: String) -> Self {
-        Self { token, v }
-    }
-}
-
-impl Node for Identifier {
-    fn token_literal(&self) -> String {
-        self.token.v.clone()
-    }
-}
-
-impl Expression for Identifier {}
\ No newline at end of file
@@ -0,0 +1,532 @@
+pub mod object;
+
+use crate::ast::{Node, Program};
+pub use object::Object;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Environment(Rc<RefCell<EnvironmentData>>);
+
+#[derive(Debug, PartialEq, Eq)]
+struct EnvironmentData {
+    store: HashMap<String, Object>,
+    outer: Option<Environment>,
+}
+
+impl Environment {
+    pub fn new() -> Self {
+        Self(Rc::new(RefCell::new(EnvironmentData {
+            store: HashMap::new(),
+            outer: None,
+        })))
+    }
+
+    pub fn new_enclosed(outer: Environment) -> Self {
+        Self(Rc::new(RefCell::new(EnvironmentData {
+            store: HashMap::new(),
+            outer: Some(outer),
+        })))
+    }
+
+    pub fn get(&self, name: &str) -> Option<Object> {
+        let data = self.0.borrow();
+        match data.store.get(name) {
+            Some(value) => Some(value.clone()),
+            None => data.outer.as_ref().and_then(|outer| outer.get(name)),
+        }
+    }
+
+    /// Shared via `Rc<RefCell<_>>` so a `let`-binding made *after* a function
+    /// literal captures its environment (e.g. binding the function's own
+    /// name for recursion) is still visible through that captured reference.
+    pub fn set(&self, name: String, value: Object) {
+        self.0.borrow_mut().store.insert(name, value);
+    }
+}
+
+impl Default for Environment {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub fn eval_program(program: &Program, env: &Environment) -> Object {
+    let mut result = Object::Null;
+
+    for statement in &program.statements {
+        result = eval(statement, env);
+
+        match result {
+            Object::ReturnValue { value } => return *value,
+            Object::Error { .. } => return result,
+            _ => {}
+        }
+    }
+
+    result
+}
+
+pub fn eval(node: &Node, env: &Environment) -> Object {
+    match node {
+        Node::ExpressionStatement { expression, .. } => match expression {
+            Some(expr) => eval(expr, env),
+            None => Object::Null,
+        },
+        Node::IntegerLiteral { value, .. } => Object::Integer { value: *value },
+        Node::StringLiteral { value, .. } => Object::String {
+            value: value.clone(),
+        },
+        Node::BooleanLiteral { value, .. } => Object::Boolean { value: *value },
+        Node::PrefixExpression {
+            operator, right, ..
+        } => {
+            let right = eval(right, env);
+            if is_error(&right) {
+                return right;
+            }
+
+            eval_prefix_expression(operator, right)
+        }
+        Node::InfixExpression {
+            left,
+            operator,
+            right,
+            ..
+        } => {
+            let left = eval(left, env);
+            if is_error(&left) {
+                return left;
+            }
+
+            let right = eval(right, env);
+            if is_error(&right) {
+                return right;
+            }
+
+            eval_infix_expression(operator, left, right)
+        }
+        Node::BlockStatement { statements } => eval_block_statement(statements, env),
+        Node::IfExpression {
+            condition,
+            consequence,
+            alternative,
+            ..
+        } => eval_if_expression(condition, consequence, alternative.as_deref(), env),
+        Node::ReturnStatement { value } => match value {
+            Some(expr) => {
+                let value = eval(expr, env);
+                if is_error(&value) {
+                    return value;
+                }
+
+                Object::ReturnValue {
+                    value: Box::new(value),
+                }
+            }
+            None => Object::ReturnValue {
+                value: Box::new(Object::Null),
+            },
+        },
+        Node::LetStatement { name, value } => {
+            let value = match value {
+                Some(expr) => eval(expr, env),
+                None => Object::Null,
+            };
+
+            if is_error(&value) {
+                return value;
+            }
+
+            if let Node::Identifier { value: token } = name.as_ref() {
+                env.set(token.v.clone(), value);
+            }
+
+            Object::Null
+        }
+        Node::Identifier { value } => match env.get(&value.v) {
+            Some(value) => value,
+            None => Object::Error {
+                value: format!("identifier not found: {}", value.v),
+            },
+        },
+        Node::FunctionLiteral {
+            parameters, body, ..
+        } => Object::Function {
+            parameters: parameters.clone(),
+            body: body.as_ref().clone(),
+            env: env.clone(),
+        },
+        Node::CallExpression {
+            function,
+            arguments,
+            ..
+        } => {
+            let function = eval(function, env);
+            if is_error(&function) {
+                return function;
+            }
+
+            let arguments = eval_expressions(arguments, env);
+            if arguments.len() == 1 && is_error(&arguments[0]) {
+                return arguments[0].clone();
+            }
+
+            apply_function(function, arguments)
+        }
+    }
+}
+
+fn eval_expressions(nodes: &[Node], env: &Environment) -> Vec<Object> {
+    let mut results = vec![];
+
+    for node in nodes {
+        let evaluated = eval(node, env);
+        let is_err = is_error(&evaluated);
+        results.push(evaluated);
+
+        if is_err {
+            break;
+        }
+    }
+
+    results
+}
+
+fn apply_function(function: Object, arguments: Vec<Object>) -> Object {
+    match function {
+        Object::Function {
+            parameters,
+            body,
+            env,
+        } => {
+            let extended_env = Environment::new_enclosed(env);
+
+            for (parameter, argument) in parameters.iter().zip(arguments) {
+                if let Node::Identifier { value } = parameter {
+                    extended_env.set(value.v.clone(), argument);
+                }
+            }
+
+            let evaluated = eval(&body, &extended_env);
+
+            match evaluated {
+                Object::ReturnValue { value } => *value,
+                other => other,
+            }
+        }
+        other => Object::Error {
+            value: format!("not a function: {}", other.name()),
+        },
+    }
+}
+
+fn eval_block_statement(statements: &[Node], env: &Environment) -> Object {
+    let mut result = Object::Null;
+
+    for statement in statements {
+        result = eval(statement, env);
+
+        match result {
+            Object::ReturnValue { .. } | Object::Error { .. } => return result,
+            _ => {}
+        }
+    }
+
+    result
+}
+
+fn eval_if_expression(
+    condition: &Node,
+    consequence: &Node,
+    alternative: Option<&Node>,
+    env: &Environment,
+) -> Object {
+    let condition = eval(condition, env);
+    if is_error(&condition) {
+        return condition;
+    }
+
+    if is_truthy(&condition) {
+        eval(consequence, env)
+    } else {
+        match alternative {
+            Some(alternative) => eval(alternative, env),
+            None => Object::Null,
+        }
+    }
+}
+
+fn is_truthy(object: &Object) -> bool {
+    match object {
+        Object::Boolean { value } => *value,
+        Object::Null => false,
+        _ => true,
+    }
+}
+
+fn is_error(object: &Object) -> bool {
+    matches!(object, Object::Error { .. })
+}
+
+fn eval_prefix_expression(operator: &str, right: Object) -> Object {
+    match operator {
+        "!" => Object::Boolean {
+            value: !is_truthy(&right),
+        },
+        "-" => match right {
+            Object::Integer { value } => Object::Integer { value: -value },
+            other => Object::Error {
+                value: format!("unknown operator: -{}", other.name()),
+            },
+        },
+        _ => Object::Error {
+            value: format!("unknown operator: {}{}", operator, right.name()),
+        },
+    }
+}
+
+fn eval_infix_expression(operator: &str, left: Object, right: Object) -> Object {
+    match (left, right) {
+        (Object::Integer { value: left }, Object::Integer { value: right }) => {
+            eval_integer_infix_expression(operator, left, right)
+        }
+        (Object::String { value: left }, Object::String { value: right }) => {
+            eval_string_infix_expression(operator, left, right)
+        }
+        (Object::Boolean { value: left }, Object::Boolean { value: right }) => match operator {
+            "==" => Object::Boolean { value: left == right },
+            "!=" => Object::Boolean { value: left != right },
+            _ => Object::Error {
+                value: format!("unknown operator: BOOLEAN {} BOOLEAN", operator),
+            },
+        },
+        (left, right) if left.name() != right.name() => Object::Error {
+            value: format!(
+                "type mismatch: {} {} {}",
+                left.name(),
+                operator,
+                right.name()
+            ),
+        },
+        (left, right) => Object::Error {
+            value: format!("unknown operator: {} {} {}", left.name(), operator, right.name()),
+        },
+    }
+}
+
+fn eval_string_infix_expression(operator: &str, left: String, right: String) -> Object {
+    match operator {
+        "+" => Object::String {
+            value: left + &right,
+        },
+        _ => Object::Error {
+            value: format!("unknown operator: STRING {} STRING", operator),
+        },
+    }
+}
+
+fn eval_integer_infix_expression(operator: &str, left: i64, right: i64) -> Object {
+    match operator {
+        "+" => left.checked_add(right).map_or_else(
+            || Object::Error {
+                value: "integer overflow".to_string(),
+            },
+            |value| Object::Integer { value },
+        ),
+        "-" => left.checked_sub(right).map_or_else(
+            || Object::Error {
+                value: "integer overflow".to_string(),
+            },
+            |value| Object::Integer { value },
+        ),
+        "*" => left.checked_mul(right).map_or_else(
+            || Object::Error {
+                value: "integer overflow".to_string(),
+            },
+            |value| Object::Integer { value },
+        ),
+        "/" => {
+            if right == 0 {
+                Object::Error {
+                    value: "division by zero".to_string(),
+                }
+            } else {
+                Object::Integer { value: left / right }
+            }
+        }
+        "<" => Object::Boolean { value: left < right },
+        ">" => Object::Boolean { value: left > right },
+        "==" => Object::Boolean { value: left == right },
+        "!=" => Object::Boolean { value: left != right },
+        _ => Object::Error {
+            value: format!("unknown operator: INTEGER {} INTEGER", operator),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lexer::Lexer;
+    use crate::parser::Parser;
+
+    fn eval_input(input: &str) -> Object {
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+        let program = parser.parse_program();
+        let env = Environment::new();
+
+        eval_program(&program, &env)
+    }
+
+    #[test]
+    fn test_eval_integer_expression() {
+        let tests = vec![
+            ("5", 5),
+            ("10", 10),
+            ("-5", -5),
+            ("5 + 5 + 5 + 5 - 10", 10),
+            ("2 * 2 * 2 * 2 * 2", 32),
+            ("(5 + 10 * 2 + 15 / 3) * 2 + -10", 50),
+        ];
+
+        for (input, expected) in tests {
+            match eval_input(input) {
+                Object::Integer { value } => assert_eq!(expected, value),
+                other => panic!("expected integer, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_eval_boolean_expression() {
+        let tests = vec![
+            ("true", true),
+            ("false", false),
+            ("1 < 2", true),
+            ("1 > 2", false),
+            ("1 == 1", true),
+            ("1 != 1", false),
+            ("true == true", true),
+            ("true != false", true),
+        ];
+
+        for (input, expected) in tests {
+            match eval_input(input) {
+                Object::Boolean { value } => assert_eq!(expected, value),
+                other => panic!("expected boolean, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_bang_operator() {
+        let tests = vec![("!true", false), ("!false", true), ("!5", false), ("!!5", true)];
+
+        for (input, expected) in tests {
+            match eval_input(input) {
+                Object::Boolean { value } => assert_eq!(expected, value),
+                other => panic!("expected boolean, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_if_else_expressions() {
+        match eval_input("if (true) { 10 }") {
+            Object::Integer { value } => assert_eq!(10, value),
+            other => panic!("expected integer, got {:?}", other),
+        }
+
+        match eval_input("if (false) { 10 }") {
+            Object::Null => {}
+            other => panic!("expected null, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_return_statements() {
+        let input = "if (10 > 1) { if (10 > 1) { return 10; } return 1; }";
+
+        match eval_input(input) {
+            Object::Integer { value } => assert_eq!(10, value),
+            other => panic!("expected integer, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_error_handling() {
+        let tests = vec![
+            ("5 + true;", "type mismatch: INTEGER + BOOLEAN"),
+            ("-true", "unknown operator: -BOOLEAN"),
+            ("true + false;", "unknown operator: BOOLEAN + BOOLEAN"),
+            ("foobar", "identifier not found: foobar"),
+            ("5 / 0;", "division by zero"),
+            ("9223372036854775807 + 1;", "integer overflow"),
+        ];
+
+        for (input, expected) in tests {
+            match eval_input(input) {
+                Object::Error { value } => assert_eq!(expected, value),
+                other => panic!("expected error, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_let_statements() {
+        let tests = vec![
+            ("let a = 5; a;", 5),
+            ("let a = 5 * 5; a;", 25),
+            ("let a = 5; let b = a; b;", 5),
+        ];
+
+        for (input, expected) in tests {
+            match eval_input(input) {
+                Object::Integer { value } => assert_eq!(expected, value),
+                other => panic!("expected integer, got {:?}", other),
+            }
+        }
+    }
+
+    #[test]
+    fn test_string_literal() {
+        match eval_input("\"Hello World!\"") {
+            Object::String { value } => assert_eq!("Hello World!", value),
+            other => panic!("expected string, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_string_concatenation() {
+        match eval_input("\"Hello\" + \" \" + \"World!\"") {
+            Object::String { value } => assert_eq!("Hello World!", value),
+            other => panic!("expected string, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_function_application() {
+        match eval_input("let identity = fn(x) { x; }; identity(5);") {
+            Object::Integer { value } => assert_eq!(5, value),
+            other => panic!("expected integer, got {:?}", other),
+        }
+
+        match eval_input("let add = fn(x, y) { x + y; }; add(5, 5);") {
+            Object::Integer { value } => assert_eq!(10, value),
+            other => panic!("expected integer, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_recursive_function_application() {
+        let input = "let fact = fn(n) { if (n == 0) { 1 } else { n * fact(n - 1) } }; fact(5);";
+
+        match eval_input(input) {
+            Object::Integer { value } => assert_eq!(120, value),
+            other => panic!("expected integer, got {:?}", other),
+        }
+    }
+}
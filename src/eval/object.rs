@@ -9,6 +9,9 @@ pub enum Object {
     Boolean {
         value: bool,
     },
+    String {
+        value: String,
+    },
     ReturnValue {
         value: Box<Object>,
     },
@@ -28,6 +31,7 @@ impl Object {
         match self {
             Object::Integer { value } => format!("{}", value),
             Object::Boolean { value } => format!("{}", value),
+            Object::String { value } => value.clone(),
             Object::ReturnValue { value } => (*value.inspect()).to_string(),
             Object::Error { value } => {
                 format!("ERROR: {}", value)
@@ -53,10 +57,11 @@ impl Object {
         match self {
             Object::Integer { .. } => "INTEGER".to_string(),
             Object::Boolean { .. } => "BOOLEAN".to_string(),
+            Object::String { .. } => "STRING".to_string(),
             Object::ReturnValue { .. } => "RETURN_VALUE".to_string(),
             Object::Error { .. } => "ERROR".to_string(),
             Object::Function { .. } => "FUNCTION".to_string(),
-            Object::Null { .. } => "NULL".to_string(),
+            Object::Null => "NULL".to_string(),
         }
     }
 }
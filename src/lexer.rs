@@ -1,75 +1,128 @@
 use crate::tokens::{Token, TokenType};
+use std::iter::Peekable;
 use std::str::Chars;
 
 pub struct Lexer<'a> {
-    pub input: Chars<'a>,
-    pub ch: char,
+    pub input: Peekable<Chars<'a>>,
+    pub ch: Option<char>,
+    line: usize,
+    column: usize,
 }
 
 impl<'a> Lexer<'a> {
     pub fn new(input_string: &'a str) -> Self {
-        let mut input = input_string.chars();
-        let initial = input.next().unwrap();
+        let mut input = input_string.chars().peekable();
+        let initial = input.next();
         Self {
-            input: input,
+            input,
             ch: initial,
+            line: 1,
+            column: 1,
         }
     }
 
     pub fn next_token(&mut self) -> Token {
         self.eat_whitespace();
 
-        let token = match self.ch {
-            '=' => Token::new(TokenType::Assign, self.ch.into()),
-            '+' => Token::new(TokenType::Plus, self.ch.into()),
-            '-' => Token::new(TokenType::Minus, self.ch.into()),
-            '!' => Token::new(TokenType::Bang, self.ch.into()),
-            '*' => Token::new(TokenType::Asterisk, self.ch.into()),
-            '/' => Token::new(TokenType::Slash, self.ch.into()),
-            '<' => Token::new(TokenType::LessThan, self.ch.into()),
-            '>' => Token::new(TokenType::GreaterThan, self.ch.into()),
-            ',' => Token::new(TokenType::Comma, self.ch.into()),
-            ';' => Token::new(TokenType::Semicolon, self.ch.into()),
-            '(' => Token::new(TokenType::LParen, self.ch.into()),
-            ')' => Token::new(TokenType::RParen, self.ch.into()),
-            '{' => Token::new(TokenType::LBrace, self.ch.into()),
-            '}' => Token::new(TokenType::RBrace, self.ch.into()),
-            '0' => Token::new(TokenType::EOF, "".into()),
-            _ => {
-                if is_letter(self.ch) {
+        let (line, column) = (self.line, self.column);
+
+        let (t, v) = match self.ch {
+            Some('=') => {
+                if self.peek_char() == Some('=') {
+                    self.read_char();
+                    (TokenType::Equal, "==".to_string())
+                } else {
+                    (TokenType::Assign, self.ch.unwrap().into())
+                }
+            }
+            Some('!') => {
+                if self.peek_char() == Some('=') {
+                    self.read_char();
+                    (TokenType::NotEqual, "!=".to_string())
+                } else {
+                    (TokenType::Bang, self.ch.unwrap().into())
+                }
+            }
+            Some('+') => (TokenType::Plus, self.ch.unwrap().into()),
+            Some('-') => (TokenType::Minus, self.ch.unwrap().into()),
+            Some('*') => (TokenType::Asterisk, self.ch.unwrap().into()),
+            Some('/') => (TokenType::Slash, self.ch.unwrap().into()),
+            Some('<') => (TokenType::LessThan, self.ch.unwrap().into()),
+            Some('>') => (TokenType::GreaterThan, self.ch.unwrap().into()),
+            Some(',') => (TokenType::Comma, self.ch.unwrap().into()),
+            Some(';') => (TokenType::Semicolon, self.ch.unwrap().into()),
+            Some('(') => (TokenType::LParen, self.ch.unwrap().into()),
+            Some(')') => (TokenType::RParen, self.ch.unwrap().into()),
+            Some('{') => (TokenType::LBrace, self.ch.unwrap().into()),
+            Some('}') => (TokenType::RBrace, self.ch.unwrap().into()),
+            Some('"') => {
+                let v = self.read_string();
+                self.read_char();
+                return Token::at(TokenType::Str, v, line, column);
+            }
+            None => return Token::at(TokenType::EOF, "".into(), line, column),
+            Some(ch) => {
+                if is_letter(ch) {
                     let v = self.read_ident(is_letter);
-                    return Token::from(v.as_str());
-                } else if is_digit(self.ch) {
+                    let token = Token::from(v.as_str());
+                    return Token::at(token.t, token.v, line, column);
+                } else if is_digit(ch) {
                     let v = self.read_ident(is_digit);
-                    return Token::new(TokenType::Int, v);
+                    return Token::at(TokenType::Int, v, line, column);
                 }
 
-                Token::new(TokenType::Illegal, self.ch.into())
+                (TokenType::Illegal, ch.into())
             }
         };
 
         self.read_char();
-        token
+        Token::at(t, v, line, column)
+    }
+
+    fn peek_char(&mut self) -> Option<char> {
+        self.input.peek().copied()
     }
 
     fn read_char(&mut self) {
-        match self.input.next() {
-            Some(ch) => self.ch = ch,
-            None => self.ch = '0',
+        if self.ch == Some('\n') {
+            self.line += 1;
+            self.column = 0;
         }
+
+        self.ch = self.input.next();
+        self.column += 1;
     }
 
     fn eat_whitespace(&mut self) {
-        while self.ch == ' ' || self.ch == '\t' || self.ch == '\n' || self.ch == '\r' {
+        while matches!(self.ch, Some(' ') | Some('\t') | Some('\n') | Some('\r')) {
+            self.read_char();
+        }
+    }
+
+    fn read_string(&mut self) -> String {
+        let mut s = String::new();
+
+        loop {
             self.read_char();
+
+            match self.ch {
+                Some('"') | None => break,
+                Some(ch) => s.push(ch),
+            }
         }
+
+        s
     }
 
     fn read_ident(&mut self, conditional: fn(char) -> bool) -> String {
         let mut ident = String::new();
 
-        while conditional(self.ch) {
-            ident.push(self.ch);
+        while let Some(ch) = self.ch {
+            if !conditional(ch) {
+                break;
+            }
+
+            ident.push(ch);
             self.read_char();
         }
 
@@ -78,9 +131,102 @@ impl<'a> Lexer<'a> {
 }
 
 fn is_letter(ch: char) -> bool {
-    'a' <= ch && ch <= 'z' || 'A' <= ch && ch <= 'Z' || ch == '_'
+    ch.is_ascii_alphabetic() || ch == '_'
 }
 
 fn is_digit(ch: char) -> bool {
-    '0' <= ch && ch <= '9'
+    ch.is_ascii_digit()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_token_handles_leading_zero() {
+        let input = "let x = 0;";
+        let mut lexer = Lexer::new(input);
+
+        let expected = vec![
+            (TokenType::Let, "let"),
+            (TokenType::Ident, "x"),
+            (TokenType::Assign, "="),
+            (TokenType::Int, "0"),
+            (TokenType::Semicolon, ";"),
+            (TokenType::EOF, ""),
+        ];
+
+        for (t, v) in expected {
+            let token = lexer.next_token();
+            assert_eq!(t, token.t);
+            assert_eq!(v, token.v);
+        }
+    }
+
+    #[test]
+    fn test_next_token_two_character_operators() {
+        let input = "10 == 10; 10 != 9;";
+        let mut lexer = Lexer::new(input);
+
+        let expected = vec![
+            (TokenType::Int, "10"),
+            (TokenType::Equal, "=="),
+            (TokenType::Int, "10"),
+            (TokenType::Semicolon, ";"),
+            (TokenType::Int, "10"),
+            (TokenType::NotEqual, "!="),
+            (TokenType::Int, "9"),
+            (TokenType::Semicolon, ";"),
+            (TokenType::EOF, ""),
+        ];
+
+        for (t, v) in expected {
+            let token = lexer.next_token();
+            assert_eq!(t, token.t);
+            assert_eq!(v, token.v);
+        }
+    }
+
+    #[test]
+    fn test_next_token_reads_string_literals() {
+        let input = "\"foobar\" \"foo bar\"";
+        let mut lexer = Lexer::new(input);
+
+        let expected = vec![
+            (TokenType::Str, "foobar"),
+            (TokenType::Str, "foo bar"),
+            (TokenType::EOF, ""),
+        ];
+
+        for (t, v) in expected {
+            let token = lexer.next_token();
+            assert_eq!(t, token.t);
+            assert_eq!(v, token.v);
+        }
+    }
+
+    #[test]
+    fn test_next_token_tracks_line_and_column() {
+        let input = "let x = 5;\nlet y = 10;";
+        let mut lexer = Lexer::new(input);
+
+        // "let"
+        let token = lexer.next_token();
+        assert_eq!(1, token.line);
+        assert_eq!(1, token.column);
+
+        // "x"
+        let token = lexer.next_token();
+        assert_eq!(1, token.line);
+        assert_eq!(5, token.column);
+
+        for _ in 0..3 {
+            lexer.next_token();
+        }
+
+        // "let" on the second line
+        let token = lexer.next_token();
+        assert_eq!(2, token.line);
+        assert_eq!(1, token.column);
+    }
 }
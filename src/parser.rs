@@ -1,8 +1,10 @@
 use crate::ast::{Node, Program};
 use crate::lexer::Lexer;
 use crate::tokens::{Token, TokenType};
+use std::collections::HashMap;
+use std::fmt;
 
-#[derive(Eq, PartialEq, Ord, PartialOrd)]
+#[derive(Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
 pub enum Precedence {
     Lowest,
     Equals,
@@ -13,18 +15,72 @@ pub enum Precedence {
     Call,
 }
 
+fn precedence_of(token_type: TokenType) -> Precedence {
+    match token_type {
+        TokenType::Equal | TokenType::NotEqual => Precedence::Equals,
+        TokenType::LessThan | TokenType::GreaterThan => Precedence::LessGreater,
+        TokenType::Plus | TokenType::Minus => Precedence::Sum,
+        TokenType::Asterisk | TokenType::Slash => Precedence::Product,
+        TokenType::LParen => Precedence::Call,
+        _ => Precedence::Lowest,
+    }
+}
+
 #[derive(Debug)]
 pub enum ParserError {
-    TokenUnrecognized,
-    IdentExpected,
-    AssignExpected,
+    UnexpectedToken { expected: TokenType, actual: Token },
+    NoPrefixParseFn { actual: Token },
+    InvalidInteger { actual: Token },
+}
+
+impl fmt::Display for ParserError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParserError::UnexpectedToken { expected, actual } => write!(
+                f,
+                "parser error at {}:{}: expected token {:?}, got {}",
+                actual.line,
+                actual.column,
+                expected,
+                format_token(actual)
+            ),
+            ParserError::NoPrefixParseFn { actual } => write!(
+                f,
+                "parser error at {}:{}: no prefix parse function for {}",
+                actual.line,
+                actual.column,
+                format_token(actual)
+            ),
+            ParserError::InvalidInteger { actual } => write!(
+                f,
+                "parser error at {}:{}: invalid integer literal {}",
+                actual.line,
+                actual.column,
+                format_token(actual)
+            ),
+        }
+    }
 }
 
+fn format_token(token: &Token) -> String {
+    match token.t {
+        TokenType::Ident | TokenType::Int | TokenType::Illegal => {
+            format!("{:?}(\"{}\")", token.t, token.v)
+        }
+        _ => format!("{:?}", token.t),
+    }
+}
+
+type PrefixParseFn<'a> = fn(&mut Parser<'a>) -> Result<Node, ParserError>;
+type InfixParseFn<'a> = fn(&mut Parser<'a>, Node) -> Result<Node, ParserError>;
+
 pub struct Parser<'a> {
     lexer: Lexer<'a>,
     curr_token: Token,
     peek_token: Token,
     pub errors: Vec<ParserError>,
+    prefix_parse_fns: HashMap<TokenType, PrefixParseFn<'a>>,
+    infix_parse_fns: HashMap<TokenType, InfixParseFn<'a>>,
 }
 
 impl<'a> Parser<'a> {
@@ -34,20 +90,54 @@ impl<'a> Parser<'a> {
             curr_token: Token::default(),
             peek_token: Token::default(),
             errors: vec![],
+            prefix_parse_fns: HashMap::new(),
+            infix_parse_fns: HashMap::new(),
         };
 
+        parser.register_prefix(TokenType::Ident, Parser::parse_identifier);
+        parser.register_prefix(TokenType::Int, Parser::parse_integer_literal);
+        parser.register_prefix(TokenType::Str, Parser::parse_string_literal);
+        parser.register_prefix(TokenType::Bang, Parser::parse_prefix_expression);
+        parser.register_prefix(TokenType::Minus, Parser::parse_prefix_expression);
+        parser.register_prefix(TokenType::LParen, Parser::parse_grouped_expression);
+        parser.register_prefix(TokenType::True, Parser::parse_boolean_literal);
+        parser.register_prefix(TokenType::False, Parser::parse_boolean_literal);
+        parser.register_prefix(TokenType::If, Parser::parse_if_expression);
+        parser.register_prefix(TokenType::Function, Parser::parse_function_literal);
+
+        parser.register_infix(TokenType::Plus, Parser::parse_infix_expression);
+        parser.register_infix(TokenType::Minus, Parser::parse_infix_expression);
+        parser.register_infix(TokenType::Asterisk, Parser::parse_infix_expression);
+        parser.register_infix(TokenType::Slash, Parser::parse_infix_expression);
+        parser.register_infix(TokenType::Equal, Parser::parse_infix_expression);
+        parser.register_infix(TokenType::NotEqual, Parser::parse_infix_expression);
+        parser.register_infix(TokenType::LessThan, Parser::parse_infix_expression);
+        parser.register_infix(TokenType::GreaterThan, Parser::parse_infix_expression);
+        parser.register_infix(TokenType::LParen, Parser::parse_call_expression);
+
         parser.next_token();
         parser.next_token();
         parser
     }
 
+    fn register_prefix(&mut self, token_type: TokenType, f: PrefixParseFn<'a>) {
+        self.prefix_parse_fns.insert(token_type, f);
+    }
+
+    fn register_infix(&mut self, token_type: TokenType, f: InfixParseFn<'a>) {
+        self.infix_parse_fns.insert(token_type, f);
+    }
+
     pub fn parse_program(&mut self) -> Program {
         let mut program = Program::default();
 
         while !self.finished() {
             match self.parse_statement() {
                 Ok(stmt) => program.statements.push(stmt),
-                Err(e) => self.errors.push(e),
+                Err(e) => {
+                    self.errors.push(e);
+                    self.synchronize();
+                }
             };
 
             self.next_token();
@@ -60,6 +150,14 @@ impl<'a> Parser<'a> {
         self.curr_token.t == TokenType::EOF
     }
 
+    /// Skips tokens up to the next statement boundary so a single malformed
+    /// statement doesn't cascade into spurious follow-on errors.
+    fn synchronize(&mut self) {
+        while !self.finished() && self.curr_token.t != TokenType::Semicolon {
+            self.next_token();
+        }
+    }
+
     fn parse_statement(&mut self) -> Result<Node, ParserError> {
         match self.curr_token.t {
             TokenType::Let => self.parse_let_statement(),
@@ -69,30 +167,38 @@ impl<'a> Parser<'a> {
     }
 
     fn parse_let_statement(&mut self) -> Result<Node, ParserError> {
-        if !self.expect_peek(TokenType::Ident) {
-            return Err(ParserError::IdentExpected);
-        }
+        self.expect_peek(TokenType::Ident)?;
 
         let ident = Node::Identifier {
             value: self.curr_token.clone(),
         };
 
-        if !self.expect_peek(TokenType::Assign) {
-            return Err(ParserError::AssignExpected);
-        }
+        self.expect_peek(TokenType::Assign)?;
 
-        self.peek_until_semicolon();
+        self.next_token();
+        let value = self.parse_expression(Precedence::Lowest)?;
+
+        if self.peek_token.t == TokenType::Semicolon {
+            self.next_token();
+        }
 
         Ok(Node::LetStatement {
             name: Box::new(ident),
-            value: None,
+            value: Some(Box::new(value)),
         })
     }
 
     fn parse_return_statement(&mut self) -> Result<Node, ParserError> {
-        self.peek_until_semicolon();
+        self.next_token();
+        let value = self.parse_expression(Precedence::Lowest)?;
 
-        Ok(Node::ReturnStatement { value: None })
+        if self.peek_token.t == TokenType::Semicolon {
+            self.next_token();
+        }
+
+        Ok(Node::ReturnStatement {
+            value: Some(Box::new(value)),
+        })
     }
 
     fn parse_expression_statement(&mut self) -> Result<Node, ParserError> {
@@ -101,34 +207,247 @@ impl<'a> Parser<'a> {
             expression: Some(Box::new(self.parse_expression(Precedence::Lowest)?)),
         };
 
-        self.peek_until_semicolon();
+        if self.peek_token.t == TokenType::Semicolon {
+            self.next_token();
+        }
+
         Ok(expr)
     }
 
     fn parse_expression(&mut self, precedence: Precedence) -> Result<Node, ParserError> {
-        match self.curr_token.t {
-            TokenType::Ident => Ok(Node::Identifier {
+        let prefix = self.prefix_parse_fns.get(&self.curr_token.t).ok_or_else(|| {
+            ParserError::NoPrefixParseFn {
+                actual: self.curr_token.clone(),
+            }
+        })?;
+
+        let mut left = prefix(self)?;
+
+        while self.peek_token.t != TokenType::Semicolon && precedence < self.peek_precedence() {
+            let infix = match self.infix_parse_fns.get(&self.peek_token.t) {
+                Some(infix) => *infix,
+                None => break,
+            };
+
+            self.next_token();
+            left = infix(self, left)?;
+        }
+
+        Ok(left)
+    }
+
+    fn parse_identifier(&mut self) -> Result<Node, ParserError> {
+        Ok(Node::Identifier {
+            value: self.curr_token.clone(),
+        })
+    }
+
+    fn parse_integer_literal(&mut self) -> Result<Node, ParserError> {
+        let value = self.curr_token.v.parse::<i64>().map_err(|_| {
+            ParserError::InvalidInteger {
+                actual: self.curr_token.clone(),
+            }
+        })?;
+
+        Ok(Node::IntegerLiteral {
+            token: self.curr_token.clone(),
+            value,
+        })
+    }
+
+    fn parse_string_literal(&mut self) -> Result<Node, ParserError> {
+        Ok(Node::StringLiteral {
+            token: self.curr_token.clone(),
+            value: self.curr_token.v.clone(),
+        })
+    }
+
+    fn parse_prefix_expression(&mut self) -> Result<Node, ParserError> {
+        let token = self.curr_token.clone();
+        let operator = token.v.clone();
+
+        self.next_token();
+        let right = self.parse_expression(Precedence::Prefix)?;
+
+        Ok(Node::PrefixExpression {
+            token,
+            operator,
+            right: Box::new(right),
+        })
+    }
+
+    fn parse_infix_expression(&mut self, left: Node) -> Result<Node, ParserError> {
+        let token = self.curr_token.clone();
+        let operator = token.v.clone();
+        let precedence = self.curr_precedence();
+
+        self.next_token();
+        let right = self.parse_expression(precedence)?;
+
+        Ok(Node::InfixExpression {
+            token,
+            left: Box::new(left),
+            operator,
+            right: Box::new(right),
+        })
+    }
+
+    fn parse_grouped_expression(&mut self) -> Result<Node, ParserError> {
+        self.next_token();
+
+        let expr = self.parse_expression(Precedence::Lowest)?;
+
+        self.expect_peek(TokenType::RParen)?;
+
+        Ok(expr)
+    }
+
+    fn parse_boolean_literal(&mut self) -> Result<Node, ParserError> {
+        Ok(Node::BooleanLiteral {
+            token: self.curr_token.clone(),
+            value: self.curr_token.t == TokenType::True,
+        })
+    }
+
+    fn parse_if_expression(&mut self) -> Result<Node, ParserError> {
+        let token = self.curr_token.clone();
+
+        self.expect_peek(TokenType::LParen)?;
+
+        self.next_token();
+        let condition = self.parse_expression(Precedence::Lowest)?;
+
+        self.expect_peek(TokenType::RParen)?;
+
+        self.expect_peek(TokenType::LBrace)?;
+
+        let consequence = self.parse_block_statement()?;
+
+        let alternative = if self.peek_token.t == TokenType::Else {
+            self.next_token();
+
+            self.expect_peek(TokenType::LBrace)?;
+
+            Some(Box::new(self.parse_block_statement()?))
+        } else {
+            None
+        };
+
+        Ok(Node::IfExpression {
+            token,
+            condition: Box::new(condition),
+            consequence: Box::new(consequence),
+            alternative,
+        })
+    }
+
+    fn parse_block_statement(&mut self) -> Result<Node, ParserError> {
+        let mut statements = vec![];
+
+        self.next_token();
+
+        while self.curr_token.t != TokenType::RBrace && !self.finished() {
+            statements.push(self.parse_statement()?);
+            self.next_token();
+        }
+
+        Ok(Node::BlockStatement { statements })
+    }
+
+    fn parse_function_literal(&mut self) -> Result<Node, ParserError> {
+        let token = self.curr_token.clone();
+
+        self.expect_peek(TokenType::LParen)?;
+
+        let parameters = self.parse_function_parameters()?;
+
+        self.expect_peek(TokenType::LBrace)?;
+
+        let body = self.parse_block_statement()?;
+
+        Ok(Node::FunctionLiteral {
+            token,
+            parameters,
+            body: Box::new(body),
+        })
+    }
+
+    fn parse_function_parameters(&mut self) -> Result<Vec<Node>, ParserError> {
+        let mut parameters = vec![];
+
+        if self.peek_token.t == TokenType::RParen {
+            self.next_token();
+            return Ok(parameters);
+        }
+
+        self.next_token();
+        parameters.push(Node::Identifier {
+            value: self.curr_token.clone(),
+        });
+
+        while self.peek_token.t == TokenType::Comma {
+            self.next_token();
+            self.next_token();
+            parameters.push(Node::Identifier {
                 value: self.curr_token.clone(),
-            }),
-            _ => Err(ParserError::TokenUnrecognized),
+            });
         }
+
+        self.expect_peek(TokenType::RParen)?;
+
+        Ok(parameters)
     }
 
-    fn peek_until_semicolon(&mut self) {
-        loop {
+    fn parse_call_expression(&mut self, function: Node) -> Result<Node, ParserError> {
+        let token = self.curr_token.clone();
+        let arguments = self.parse_call_arguments()?;
+
+        Ok(Node::CallExpression {
+            token,
+            function: Box::new(function),
+            arguments,
+        })
+    }
+
+    fn parse_call_arguments(&mut self) -> Result<Vec<Node>, ParserError> {
+        let mut arguments = vec![];
+
+        if self.peek_token.t == TokenType::RParen {
             self.next_token();
-            if self.curr_token.t == TokenType::Semicolon {
-                break;
-            }
+            return Ok(arguments);
         }
+
+        self.next_token();
+        arguments.push(self.parse_expression(Precedence::Lowest)?);
+
+        while self.peek_token.t == TokenType::Comma {
+            self.next_token();
+            self.next_token();
+            arguments.push(self.parse_expression(Precedence::Lowest)?);
+        }
+
+        self.expect_peek(TokenType::RParen)?;
+
+        Ok(arguments)
+    }
+
+    fn peek_precedence(&self) -> Precedence {
+        precedence_of(self.peek_token.t)
+    }
+
+    fn curr_precedence(&self) -> Precedence {
+        precedence_of(self.curr_token.t)
     }
 
-    fn expect_peek(&mut self, token_type: TokenType) -> bool {
+    fn expect_peek(&mut self, token_type: TokenType) -> Result<(), ParserError> {
         if self.peek_token.t == token_type {
             self.next_token();
-            true
+            Ok(())
         } else {
-            false
+            Err(ParserError::UnexpectedToken {
+                expected: token_type,
+                actual: self.peek_token.clone(),
+            })
         }
     }
 
@@ -149,7 +468,7 @@ mod tests {
         let y = 10;
         let z = 838383;";
 
-        let mut lexer = Lexer::new(input);
+        let lexer = Lexer::new(input);
         let mut parser = Parser::new(lexer);
 
         let program = parser.parse_program();
@@ -163,7 +482,7 @@ mod tests {
         assert_eq!(String::from("let"), first_statement.token_literal());
         if let Node::LetStatement { name, value } = first_statement {
             assert_eq!(String::from("x"), name.token_literal());
-            assert!(value.is_none());
+            assert_eq!(Some(String::from("5")), value.as_ref().map(|v| v.as_string()));
         } else {
             panic!("expected let statement");
         }
@@ -172,7 +491,7 @@ mod tests {
         assert_eq!(String::from("let"), second_statement.token_literal());
         if let Node::LetStatement { name, value } = second_statement {
             assert_eq!(String::from("y"), name.token_literal());
-            assert!(value.is_none());
+            assert_eq!(Some(String::from("10")), value.as_ref().map(|v| v.as_string()));
         } else {
             panic!("expected let statement");
         }
@@ -181,7 +500,10 @@ mod tests {
         assert_eq!(String::from("let"), third_statement.token_literal());
         if let Node::LetStatement { name, value } = third_statement {
             assert_eq!(String::from("z"), name.token_literal());
-            assert!(value.is_none());
+            assert_eq!(
+                Some(String::from("838383")),
+                value.as_ref().map(|v| v.as_string())
+            );
         } else {
             panic!("expected let statement");
         }
@@ -194,7 +516,7 @@ mod tests {
         return 10;
         return 987235;";
 
-        let mut lexer = Lexer::new(input);
+        let lexer = Lexer::new(input);
         let mut parser = Parser::new(lexer);
 
         let program = parser.parse_program();
@@ -207,7 +529,7 @@ mod tests {
         let first_statement = iter.next().expect("should contain a statement");
         assert_eq!(String::from("return"), first_statement.token_literal());
         if let Node::ReturnStatement { value } = first_statement {
-            assert!(value.is_none());
+            assert_eq!(Some(String::from("5")), value.as_ref().map(|v| v.as_string()));
         } else {
             panic!("expected return statement");
         }
@@ -217,7 +539,7 @@ mod tests {
     fn test_identifier_expression() {
         let input = "foobar;";
 
-        let mut lexer = Lexer::new(input);
+        let lexer = Lexer::new(input);
         let mut parser = Parser::new(lexer);
 
         let program = parser.parse_program();
@@ -236,8 +558,316 @@ mod tests {
         assert_eq!(stmt.token_literal(), "foobar".to_string());
     }
 
+    #[test]
+    fn test_integer_literal_expression() {
+        let input = "5;";
+
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+
+        let program = parser.parse_program();
+        assert!(!did_parser_fail(parser.errors));
+
+        assert_eq!(1, program.statements.len());
+
+        if let Node::ExpressionStatement {
+            expression: Some(expr),
+            ..
+        } = &program.statements[0]
+        {
+            if let Node::IntegerLiteral { value, .. } = expr.as_ref() {
+                assert_eq!(5, *value);
+            } else {
+                panic!("expected integer literal");
+            }
+        } else {
+            panic!("expected expression statement");
+        }
+    }
+
+    #[test]
+    fn test_parsing_prefix_expressions() {
+        let tests = vec![("!5;", "!", 5), ("-15;", "-", 15)];
+
+        for (input, operator, value) in tests {
+            let lexer = Lexer::new(input);
+            let mut parser = Parser::new(lexer);
+
+            let program = parser.parse_program();
+            assert!(!did_parser_fail(parser.errors));
+            assert_eq!(1, program.statements.len());
+
+            if let Node::ExpressionStatement {
+                expression: Some(expr),
+                ..
+            } = &program.statements[0]
+            {
+                if let Node::PrefixExpression {
+                    operator: op,
+                    right,
+                    ..
+                } = expr.as_ref()
+                {
+                    assert_eq!(operator, op);
+                    if let Node::IntegerLiteral { value: v, .. } = right.as_ref() {
+                        assert_eq!(value, *v);
+                    } else {
+                        panic!("expected integer literal");
+                    }
+                } else {
+                    panic!("expected prefix expression");
+                }
+            } else {
+                panic!("expected expression statement");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parsing_infix_expressions() {
+        let tests = vec![
+            ("5 + 5;", 5, "+", 5),
+            ("5 - 5;", 5, "-", 5),
+            ("5 * 5;", 5, "*", 5),
+            ("5 / 5;", 5, "/", 5),
+            ("5 < 5;", 5, "<", 5),
+            ("5 > 5;", 5, ">", 5),
+            ("5 == 5;", 5, "==", 5),
+            ("5 != 5;", 5, "!=", 5),
+        ];
+
+        for (input, left_value, operator, right_value) in tests {
+            let lexer = Lexer::new(input);
+            let mut parser = Parser::new(lexer);
+
+            let program = parser.parse_program();
+            assert!(!did_parser_fail(parser.errors));
+            assert_eq!(1, program.statements.len());
+
+            if let Node::ExpressionStatement {
+                expression: Some(expr),
+                ..
+            } = &program.statements[0]
+            {
+                if let Node::InfixExpression {
+                    left,
+                    operator: op,
+                    right,
+                    ..
+                } = expr.as_ref()
+                {
+                    assert_eq!(operator, op);
+
+                    if let Node::IntegerLiteral { value, .. } = left.as_ref() {
+                        assert_eq!(left_value, *value);
+                    } else {
+                        panic!("expected integer literal");
+                    }
+
+                    if let Node::IntegerLiteral { value, .. } = right.as_ref() {
+                        assert_eq!(right_value, *value);
+                    } else {
+                        panic!("expected integer literal");
+                    }
+                } else {
+                    panic!("expected infix expression");
+                }
+            } else {
+                panic!("expected expression statement");
+            }
+        }
+    }
+
+    #[test]
+    fn test_operator_precedence_parsing() {
+        let tests = vec![
+            ("1 + 2 * 3;", "(1 + (2 * 3))"),
+            ("(1 + 2) * 3;", "((1 + 2) * 3)"),
+            ("-a * b", "((-a) * b)"),
+            ("a + b - c", "((a + b) - c)"),
+        ];
+
+        for (input, expected) in tests {
+            let lexer = Lexer::new(input);
+            let mut parser = Parser::new(lexer);
+
+            let program = parser.parse_program();
+            assert!(!did_parser_fail(parser.errors));
+
+            let actual = program
+                .statements
+                .iter()
+                .map(|s| s.as_string())
+                .collect::<Vec<String>>()
+                .join("");
+            assert_eq!(expected, actual);
+        }
+    }
+
+    #[test]
+    fn test_string_literal_expression() {
+        let input = "\"hello world\";";
+
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+
+        let program = parser.parse_program();
+        assert!(!did_parser_fail(parser.errors));
+        assert_eq!(1, program.statements.len());
+
+        if let Node::ExpressionStatement {
+            expression: Some(expr),
+            ..
+        } = &program.statements[0]
+        {
+            if let Node::StringLiteral { value, .. } = expr.as_ref() {
+                assert_eq!("hello world", value);
+            } else {
+                panic!("expected string literal");
+            }
+        } else {
+            panic!("expected expression statement");
+        }
+    }
+
+    #[test]
+    fn test_integer_literal_overflow_is_reported() {
+        let input = "99999999999999999999;";
+
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+
+        parser.parse_program();
+
+        assert_eq!(1, parser.errors.len());
+        assert!(matches!(
+            parser.errors[0],
+            ParserError::InvalidInteger { .. }
+        ));
+    }
+
+    #[test]
+    fn test_if_expression() {
+        let input = "if (x < y) { x } else { y }";
+
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+
+        let program = parser.parse_program();
+        assert!(!did_parser_fail(parser.errors));
+        assert_eq!(1, program.statements.len());
+
+        if let Node::ExpressionStatement {
+            expression: Some(expr),
+            ..
+        } = &program.statements[0]
+        {
+            if let Node::IfExpression {
+                alternative,
+                consequence,
+                ..
+            } = expr.as_ref()
+            {
+                assert_eq!("x", consequence.as_string());
+                assert!(alternative.is_some());
+            } else {
+                panic!("expected if expression");
+            }
+        } else {
+            panic!("expected expression statement");
+        }
+    }
+
+    #[test]
+    fn test_function_literal_parsing() {
+        let input = "fn(x, y) { x + y; }";
+
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+
+        let program = parser.parse_program();
+        assert!(!did_parser_fail(parser.errors));
+        assert_eq!(1, program.statements.len());
+
+        if let Node::ExpressionStatement {
+            expression: Some(expr),
+            ..
+        } = &program.statements[0]
+        {
+            if let Node::FunctionLiteral { parameters, .. } = expr.as_ref() {
+                assert_eq!(2, parameters.len());
+                assert_eq!("x", parameters[0].token_literal());
+                assert_eq!("y", parameters[1].token_literal());
+            } else {
+                panic!("expected function literal");
+            }
+        } else {
+            panic!("expected expression statement");
+        }
+    }
+
+    #[test]
+    fn test_call_expression_parsing() {
+        let input = "add(1, 2 * 3, 4 + 5);";
+
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+
+        let program = parser.parse_program();
+        assert!(!did_parser_fail(parser.errors));
+        assert_eq!(1, program.statements.len());
+
+        if let Node::ExpressionStatement {
+            expression: Some(expr),
+            ..
+        } = &program.statements[0]
+        {
+            if let Node::CallExpression {
+                function,
+                arguments,
+                ..
+            } = expr.as_ref()
+            {
+                assert_eq!("add", function.as_string());
+                assert_eq!(3, arguments.len());
+            } else {
+                panic!("expected call expression");
+            }
+        } else {
+            panic!("expected expression statement");
+        }
+    }
+
+    #[test]
+    fn test_parser_errors_report_position_and_tokens() {
+        let input = "let x 5;";
+
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+
+        parser.parse_program();
+
+        assert_eq!(1, parser.errors.len());
+        assert_eq!(
+            "parser error at 1:7: expected token Assign, got Int(\"5\")",
+            parser.errors[0].to_string()
+        );
+    }
+
+    #[test]
+    fn test_parser_reports_every_failure_not_just_the_first() {
+        let input = "let x 5; let y 10;";
+
+        let lexer = Lexer::new(input);
+        let mut parser = Parser::new(lexer);
+
+        parser.parse_program();
+
+        assert_eq!(2, parser.errors.len());
+    }
+
     fn did_parser_fail(errors: Vec<ParserError>) -> bool {
-        if errors.len() == 0 {
+        if errors.is_empty() {
             false
         } else {
             errors.iter().for_each(|e| {
@@ -0,0 +1,86 @@
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum TokenType {
+    #[default]
+    Illegal,
+    EOF,
+
+    Ident,
+    Int,
+    Str,
+
+    Assign,
+    Plus,
+    Minus,
+    Bang,
+    Asterisk,
+    Slash,
+
+    LessThan,
+    GreaterThan,
+    Equal,
+    NotEqual,
+
+    Comma,
+    Semicolon,
+
+    LParen,
+    RParen,
+    LBrace,
+    RBrace,
+
+    Function,
+    Let,
+    True,
+    False,
+    If,
+    Else,
+    Return,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Token {
+    pub t: TokenType,
+    pub v: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Token {
+    pub fn new(t: TokenType, v: String) -> Self {
+        Self {
+            t,
+            v,
+            line: 0,
+            column: 0,
+        }
+    }
+
+    pub fn at(t: TokenType, v: String, line: usize, column: usize) -> Self {
+        Self { t, v, line, column }
+    }
+}
+
+impl PartialEq for Token {
+    fn eq(&self, other: &Self) -> bool {
+        self.t == other.t && self.v == other.v
+    }
+}
+
+impl Eq for Token {}
+
+impl From<&str> for Token {
+    fn from(ident: &str) -> Self {
+        let t = match ident {
+            "fn" => TokenType::Function,
+            "let" => TokenType::Let,
+            "true" => TokenType::True,
+            "false" => TokenType::False,
+            "if" => TokenType::If,
+            "else" => TokenType::Else,
+            "return" => TokenType::Return,
+            _ => TokenType::Ident,
+        };
+
+        Token::new(t, ident.to_string())
+    }
+}